@@ -14,12 +14,15 @@ use opentelemetry_sdk::{
     metrics::{
         data::{ResourceMetrics, Temporality},
         exporter::PushMetricsExporter,
-        PeriodicReader, SdkMeterProvider,
+        InstrumentKind, PeriodicReader, SdkMeterProvider,
     },
     runtime::Runtime,
     Resource,
 };
 use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 use std::time;
 
 #[cfg(feature = "http-proto")]
@@ -47,10 +50,14 @@ impl OtlpPipeline {
         OtlpMetricPipeline {
             rt,
             temporality: None,
+            temporality_selector: None,
             exporter_pipeline: NoExporterConfig(()),
             resource: None,
             period: None,
             timeout: None,
+            retry_policy: None,
+            config_provider: None,
+            metric_filter: None,
         }
     }
 }
@@ -74,15 +81,22 @@ pub enum MetricsExporterBuilder {
 
 impl MetricsExporterBuilder {
     /// Build a OTLP metrics exporter with given configuration.
-    pub fn build_metrics_exporter(self, temporality: Temporality) -> Result<MetricsExporter> {
+    pub fn build_metrics_exporter(
+        self,
+        temporality_selector: Box<dyn TemporalitySelector>,
+    ) -> Result<MetricsExporter> {
         match self {
             #[cfg(feature = "grpc-tonic")]
-            MetricsExporterBuilder::Tonic(builder) => builder.build_metrics_exporter(temporality),
+            MetricsExporterBuilder::Tonic(builder) => {
+                builder.build_metrics_exporter(temporality_selector)
+            }
             #[cfg(feature = "http-proto")]
-            MetricsExporterBuilder::Http(builder) => builder.build_metrics_exporter(temporality),
+            MetricsExporterBuilder::Http(builder) => {
+                builder.build_metrics_exporter(temporality_selector)
+            }
             #[cfg(not(any(feature = "http-proto", feature = "grpc-tonic")))]
             MetricsExporterBuilder::Unconfigured => {
-                let _ = temporality;
+                let _ = temporality_selector;
                 Err(opentelemetry::metrics::MetricsError::Other(
                     "no configured metrics exporter, enable `http-proto` or `grpc-tonic` feature to configure a metrics exporter".into(),
                 ))
@@ -105,6 +119,206 @@ impl From<HttpExporterBuilder> for MetricsExporterBuilder {
     }
 }
 
+/// Maps an [InstrumentKind] to the [Temporality] the exporter should request
+/// for it.
+///
+/// # Limitation
+///
+/// The SDK's [`PushMetricsExporter`] contract exposes only `fn
+/// temporality(&self) -> Temporality`, with no instrument kind, so the SDK
+/// applies a **single** value to every instrument when building aggregations.
+/// [MetricsExporter] reports the value this selector returns for
+/// [`InstrumentKind::Counter`] as that single value, so a selector that varies
+/// by kind would be silently collapsed and produce wrong temporality for the
+/// other kinds. For that reason only the uniform [`all_cumulative`] and
+/// [`all_delta`] selectors are provided; per-kind presets will be added once
+/// the SDK consults the selector per kind.
+pub trait TemporalitySelector: Send + Sync {
+    /// The [Temporality] to use for the given instrument kind.
+    fn temporality(&self, kind: InstrumentKind) -> Temporality;
+}
+
+/// A [TemporalitySelector] that returns the same [Temporality] for every
+/// instrument kind.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstantTemporalitySelector {
+    temporality: Temporality,
+}
+
+impl ConstantTemporalitySelector {
+    /// Create a selector that always returns `temporality`.
+    pub fn new(temporality: Temporality) -> Self {
+        ConstantTemporalitySelector { temporality }
+    }
+}
+
+impl TemporalitySelector for ConstantTemporalitySelector {
+    fn temporality(&self, _kind: InstrumentKind) -> Temporality {
+        self.temporality
+    }
+}
+
+/// A [TemporalitySelector] requesting [Temporality::Cumulative] for every
+/// instrument kind.
+pub fn all_cumulative() -> ConstantTemporalitySelector {
+    ConstantTemporalitySelector::new(Temporality::Cumulative)
+}
+
+/// A [TemporalitySelector] requesting [Temporality::Delta] for every instrument
+/// kind.
+pub fn all_delta() -> ConstantTemporalitySelector {
+    ConstantTemporalitySelector::new(Temporality::Delta)
+}
+
+/// Fallback interval pinned on the wrapped [`PeriodicReader`] when a
+/// [ConfigProvider] drives the cadence, so the reader's own timer effectively
+/// never fires on its own.
+const FALLBACK_INTERVAL: time::Duration = time::Duration::from_secs(60 * 60 * 24 * 365);
+
+/// Supplies the export interval and timeout to the periodic exporter, re-read
+/// on every collection cycle so operators can retune the cadence (or disable
+/// exporting) at runtime without restarting the process.
+pub trait ConfigProvider: Send + Sync {
+    /// How long to wait between export cycles. Re-read each cycle and fully
+    /// honored: it drives the delay before the next collection.
+    fn export_interval(&self) -> time::Duration;
+
+    /// Maximum time a single export should take.
+    ///
+    /// **Advisory only.** The actual RPC deadline is the build-time timeout
+    /// frozen into the `PeriodicReader`; this value is re-read each cycle solely
+    /// to detect and report a cycle that overran it. Changing it at runtime does
+    /// not shorten or extend the export RPC.
+    fn timeout(&self) -> time::Duration;
+
+    /// When `true`, the current cycle is skipped and nothing is exported.
+    fn disabled(&self) -> bool {
+        false
+    }
+}
+
+/// Decides, per metric stream, whether it should be exported.
+///
+/// Applied inside [MetricsExporter::export] over the [ResourceMetrics] before
+/// they are handed to the [MetricsClient], so dropped streams are never
+/// serialized. Use it to cut cardinality or cost at the edge (for example to
+/// drop noisy histogram families) without reconfiguring the collector.
+pub trait MetricFilter: Send + Sync {
+    /// Return `true` to keep the metric, `false` to drop it.
+    fn keep(&self, scope_name: &str, instrument_name: &str) -> bool;
+}
+
+impl<F> MetricFilter for F
+where
+    F: Fn(&str, &str) -> bool + Send + Sync,
+{
+    fn keep(&self, scope_name: &str, instrument_name: &str) -> bool {
+        (self)(scope_name, instrument_name)
+    }
+}
+
+/// A [MetricFilter] that keeps only instruments whose name starts with one of
+/// the configured prefixes.
+#[derive(Debug, Clone)]
+pub struct PrefixAllowList {
+    prefixes: Vec<String>,
+}
+
+impl PrefixAllowList {
+    /// Keep instruments whose name starts with any of `prefixes`.
+    pub fn new<I, S>(prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        PrefixAllowList {
+            prefixes: prefixes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl MetricFilter for PrefixAllowList {
+    fn keep(&self, _scope_name: &str, instrument_name: &str) -> bool {
+        self.prefixes
+            .iter()
+            .any(|prefix| instrument_name.starts_with(prefix))
+    }
+}
+
+/// A [MetricFilter] that drops instruments whose name matches any of the
+/// configured regular expressions.
+///
+/// Requires the optional `regex` feature, so callers who only need
+/// [PrefixAllowList] do not pull in the regex engine.
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct RegexDenyList {
+    patterns: Vec<regex::Regex>,
+}
+
+#[cfg(feature = "regex")]
+impl RegexDenyList {
+    /// Drop instruments whose name matches any of `patterns`.
+    pub fn new<I, S>(patterns: I) -> std::result::Result<Self, regex::Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|p| regex::Regex::new(p.as_ref()))
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(RegexDenyList { patterns })
+    }
+}
+
+#[cfg(feature = "regex")]
+impl MetricFilter for RegexDenyList {
+    fn keep(&self, _scope_name: &str, instrument_name: &str) -> bool {
+        !self
+            .patterns
+            .iter()
+            .any(|pattern| pattern.is_match(instrument_name))
+    }
+}
+
+/// Policy controlling how transient OTLP export failures are retried.
+///
+/// Each retry sleeps for an exponentially growing interval with full jitter
+/// (`delay = random_between(0, min(max_interval, initial_interval * multiplier^attempt))`),
+/// except that a `Retry-After` delay found in the error is used as-is instead,
+/// and gives up once either [`RetryPolicy::max_attempts`] is reached or
+/// [`RetryPolicy::max_elapsed_time`] has elapsed, returning the last error.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of export attempts, including the initial one.
+    pub max_attempts: usize,
+    /// Upper bound of the delay before the first retry, before jitter is applied.
+    pub initial_interval: time::Duration,
+    /// Factor by which the delay grows after each failed attempt.
+    pub multiplier: f64,
+    /// Upper bound for a single retry delay before jitter is applied.
+    pub max_interval: time::Duration,
+    /// Total time budget across all attempts.
+    pub max_elapsed_time: time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_interval: time::Duration::from_secs(1),
+            multiplier: 1.5,
+            max_interval: time::Duration::from_secs(5),
+            max_elapsed_time: time::Duration::from_secs(30),
+        }
+    }
+}
+
+/// Type-erased, runtime-backed sleep used by the retry loop so that the wait
+/// goes through the pipeline [`Runtime`]'s timer rather than `tokio::time`.
+type SleepFn = Box<dyn Fn(time::Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
 /// Pipeline to build OTLP metrics exporter
 ///
 /// Note that currently the OTLP metrics exporter only supports tonic as it's grpc layer and tokio as
@@ -112,10 +326,14 @@ impl From<HttpExporterBuilder> for MetricsExporterBuilder {
 pub struct OtlpMetricPipeline<RT, EB> {
     rt: RT,
     temporality: Option<Temporality>,
+    temporality_selector: Option<Box<dyn TemporalitySelector>>,
     exporter_pipeline: EB,
     resource: Option<Resource>,
     period: Option<time::Duration>,
     timeout: Option<time::Duration>,
+    retry_policy: Option<RetryPolicy>,
+    config_provider: Option<Arc<dyn ConfigProvider>>,
+    metric_filter: Option<Box<dyn MetricFilter>>,
 }
 
 impl<RT, EB> OtlpMetricPipeline<RT, EB>
@@ -146,13 +364,84 @@ where
         }
     }
 
-    /// Set the [Temporality] of the exporter.
+    /// Set a single [Temporality] applied to every instrument.
+    ///
+    /// To supply the value through a [TemporalitySelector] use
+    /// [`with_temporality_selector`].
+    ///
+    /// [`with_temporality_selector`]: OtlpMetricPipeline::with_temporality_selector
     pub fn with_temporality(self, temporality: Temporality) -> Self {
         OtlpMetricPipeline {
             temporality: Some(temporality),
             ..self
         }
     }
+
+    /// Set the exporter [Temporality] via a [TemporalitySelector].
+    ///
+    /// Takes precedence over [`with_temporality`]. The built-in uniform
+    /// selectors [`all_cumulative`] and [`all_delta`] are the supported inputs;
+    /// see the limitation note on [TemporalitySelector] for why per-kind
+    /// selectors are not yet exposed.
+    ///
+    /// [`with_temporality`]: OtlpMetricPipeline::with_temporality
+    pub fn with_temporality_selector<S>(self, selector: S) -> Self
+    where
+        S: TemporalitySelector + 'static,
+    {
+        OtlpMetricPipeline {
+            temporality_selector: Some(Box::new(selector)),
+            ..self
+        }
+    }
+
+    /// Retry transient export failures according to the given [RetryPolicy].
+    ///
+    /// When set, [MetricsExporter::export] classifies the error returned by the
+    /// underlying [MetricsClient] and, for retryable errors (connection errors,
+    /// gRPC `UNAVAILABLE`/`RESOURCE_EXHAUSTED`/`ABORTED`, HTTP 429/502/503/504),
+    /// backs off and retries until the policy is exhausted.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        OtlpMetricPipeline {
+            retry_policy: Some(retry_policy),
+            ..self
+        }
+    }
+
+    /// Drive the export interval from a [ConfigProvider], re-read on every cycle.
+    ///
+    /// The interval takes precedence over [`with_period`]; the provider can also
+    /// pause exporting via [`ConfigProvider::disabled`]. The RPC deadline still
+    /// comes from [`with_timeout`] — [`ConfigProvider::timeout`] is advisory
+    /// (see its documentation).
+    ///
+    /// [`with_period`]: OtlpMetricPipeline::with_period
+    /// [`with_timeout`]: OtlpMetricPipeline::with_timeout
+    pub fn with_config_provider<C>(self, config_provider: C) -> Self
+    where
+        C: ConfigProvider + 'static,
+    {
+        OtlpMetricPipeline {
+            config_provider: Some(Arc::new(config_provider)),
+            ..self
+        }
+    }
+
+    /// Filter which metric streams are exported.
+    ///
+    /// The filter is consulted for every `(scope name, instrument name)` pair
+    /// and dropped streams are removed before serialization. See
+    /// [`PrefixAllowList`] and `RegexDenyList` (the latter behind the `regex`
+    /// feature) for ready-made filters.
+    pub fn with_metric_filter<F>(self, metric_filter: F) -> Self
+    where
+        F: MetricFilter + 'static,
+    {
+        OtlpMetricPipeline {
+            metric_filter: Some(Box::new(metric_filter)),
+            ..self
+        }
+    }
 }
 
 impl<RT> OtlpMetricPipeline<RT, NoExporterConfig>
@@ -168,9 +457,13 @@ where
             exporter_pipeline: pipeline.into(),
             rt: self.rt,
             temporality: self.temporality,
+            temporality_selector: self.temporality_selector,
             resource: self.resource,
             period: self.period,
             timeout: self.timeout,
+            retry_policy: self.retry_policy,
+            config_provider: self.config_provider,
+            metric_filter: self.metric_filter,
         }
     }
 }
@@ -181,17 +474,47 @@ where
 {
     /// Build MeterProvider
     pub fn build(self) -> Result<SdkMeterProvider> {
-        let exporter = self
+        let temporality_selector = self.temporality_selector.unwrap_or_else(|| {
+            Box::new(ConstantTemporalitySelector::new(
+                self.temporality.unwrap_or_default(),
+            ))
+        });
+
+        let mut exporter = self
             .exporter_pipeline
-            .build_metrics_exporter(self.temporality.unwrap_or_default())?;
+            .build_metrics_exporter(temporality_selector)?;
 
-        let mut builder = PeriodicReader::builder(exporter, self.rt);
+        if let Some(metric_filter) = self.metric_filter {
+            exporter = exporter.with_metric_filter(metric_filter);
+        }
 
-        if let Some(period) = self.period {
-            builder = builder.with_interval(period);
+        if let Some(retry_policy) = self.retry_policy {
+            let rt = self.rt.clone();
+            exporter = exporter.with_retry_policy(retry_policy, move |delay| {
+                let rt = rt.clone();
+                Box::pin(async move {
+                    rt.delay(delay).await;
+                })
+            });
         }
-        if let Some(timeout) = self.timeout {
-            builder = builder.with_timeout(timeout)
+
+        let mut builder = PeriodicReader::builder(exporter, self.rt);
+
+        if self.config_provider.is_some() {
+            // The provider owns the cadence: pin the reader's own interval to a
+            // large fallback so the only exports are the ones our loop triggers.
+            // The RPC deadline stays the authoritative build-time timeout.
+            builder = builder.with_interval(FALLBACK_INTERVAL);
+            if let Some(timeout) = self.timeout {
+                builder = builder.with_timeout(timeout);
+            }
+        } else {
+            if let Some(period) = self.period {
+                builder = builder.with_interval(period);
+            }
+            if let Some(timeout) = self.timeout {
+                builder = builder.with_timeout(timeout)
+            }
         }
 
         let reader = builder.build();
@@ -203,6 +526,45 @@ where
         }
 
         let provider = provider.build();
+
+        if let Some(config_provider) = self.config_provider {
+            // Re-read the interval, timeout and `disabled` flag on every cycle so
+            // the cadence can be retuned at runtime. `force_flush` blocks the
+            // calling thread on the export future, so drive it from a dedicated
+            // OS thread rather than a runtime worker, which it could otherwise
+            // stall.
+            let meter_provider = provider.clone();
+            std::thread::spawn(move || loop {
+                std::thread::sleep(config_provider.export_interval());
+                if config_provider.disabled() {
+                    continue;
+                }
+
+                // The reader applies the authoritative build-time timeout to the
+                // RPC; the provider's `timeout()` is advisory and only used to
+                // surface a cycle that overran it.
+                let timeout = config_provider.timeout();
+                let started = time::Instant::now();
+                match meter_provider.force_flush() {
+                    Ok(()) => {
+                        if started.elapsed() > timeout {
+                            opentelemetry::global::handle_error(
+                                opentelemetry::metrics::MetricsError::Other(format!(
+                                    "metric export exceeded advisory timeout of {timeout:?}"
+                                )),
+                            );
+                        }
+                    }
+                    Err(err) => {
+                        // A shut-down provider returns an error here; stop the
+                        // loop rather than spinning on a dead provider.
+                        opentelemetry::global::handle_error(err);
+                        break;
+                    }
+                }
+            });
+        }
+
         Ok(provider)
     }
 }
@@ -214,6 +576,9 @@ impl<RT, EB: Debug> Debug for OtlpMetricPipeline<RT, EB> {
             .field("resource", &self.resource)
             .field("period", &self.period)
             .field("timeout", &self.timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("config_provider", &self.config_provider.is_some())
+            .field("metric_filter", &self.metric_filter.is_some())
             .finish()
     }
 }
@@ -221,14 +586,62 @@ impl<RT, EB: Debug> Debug for OtlpMetricPipeline<RT, EB> {
 /// An interface for OTLP metrics clients
 #[async_trait]
 pub trait MetricsClient: fmt::Debug + Send + Sync + 'static {
-    async fn export(&self, metrics: &mut ResourceMetrics) -> Result<()>;
+    /// Export `metrics`, returning any [PartialSuccess] the collector reported.
+    ///
+    /// The tonic and http implementations decode the
+    /// `ExportMetricsServiceResponse` and return its `partial_success` field; a
+    /// fully successful export returns [PartialSuccess::default].
+    async fn export(&self, metrics: &mut ResourceMetrics) -> Result<PartialSuccess>;
     fn shutdown(&self) -> Result<()>;
 }
 
+/// The `partial_success` field of an OTLP `ExportMetricsServiceResponse`.
+///
+/// A collector may accept a batch yet reject some of its data points (for
+/// example on schema or cardinality violations). The tonic and http
+/// [MetricsClient] implementations decode the response into this struct and
+/// [MetricsExporter::export] passes it to [handle_partial_success] so the
+/// rejections are surfaced instead of looking like a fully successful export.
+#[derive(Debug, Default, Clone)]
+pub struct PartialSuccess {
+    /// Number of data points the collector rejected.
+    pub rejected_data_points: i64,
+    /// Human-readable detail about the rejection, if any.
+    pub error_message: String,
+}
+
+/// Inspect an OTLP partial-success payload and, when it reports rejected data
+/// points or a non-empty message, emit a warning through
+/// [`opentelemetry::global`] error handling.
+///
+/// Returns the number of rejected data points so callers can expose it.
+pub(crate) fn handle_partial_success(partial_success: PartialSuccess) -> i64 {
+    let PartialSuccess {
+        rejected_data_points,
+        error_message,
+    } = partial_success;
+
+    if rejected_data_points > 0 || !error_message.is_empty() {
+        let message = if error_message.is_empty() {
+            format!("OTLP partial success: {rejected_data_points} data points rejected")
+        } else {
+            format!(
+                "OTLP partial success: {rejected_data_points} data points rejected: {error_message}"
+            )
+        };
+        opentelemetry::global::handle_error(opentelemetry::metrics::MetricsError::Other(message));
+    }
+
+    rejected_data_points
+}
+
 /// Export metrics in OTEL format.
 pub struct MetricsExporter {
     client: Box<dyn MetricsClient>,
-    temporality: Temporality,
+    temporality_selector: Box<dyn TemporalitySelector>,
+    retry_policy: Option<RetryPolicy>,
+    sleep: Option<SleepFn>,
+    metric_filter: Option<Box<dyn MetricFilter>>,
 }
 
 impl Debug for MetricsExporter {
@@ -240,7 +653,18 @@ impl Debug for MetricsExporter {
 #[async_trait]
 impl PushMetricsExporter for MetricsExporter {
     async fn export(&self, metrics: &mut ResourceMetrics) -> Result<()> {
-        self.client.export(metrics).await
+        if let Some(filter) = &self.metric_filter {
+            apply_metric_filter(filter.as_ref(), metrics);
+        }
+
+        match (&self.retry_policy, &self.sleep) {
+            (Some(policy), Some(sleep)) => self.export_with_retry(policy, sleep, metrics).await,
+            _ => {
+                let partial_success = self.client.export(metrics).await?;
+                handle_partial_success(partial_success);
+                Ok(())
+            }
+        }
     }
 
     async fn force_flush(&self) -> Result<()> {
@@ -253,16 +677,330 @@ impl PushMetricsExporter for MetricsExporter {
     }
 
     fn temporality(&self) -> Temporality {
-        self.temporality
+        // The SDK's push-exporter contract exposes a single temporality with no
+        // instrument kind, so we report the value the selector returns for
+        // synchronous counters. This is exact for the uniform selectors
+        // (`all_cumulative` / `all_delta`); a selector that varies by kind is
+        // collapsed to its counter value. See the limitation note on
+        // `TemporalitySelector`.
+        self.temporality_selector
+            .temporality(InstrumentKind::Counter)
     }
 }
 
 impl MetricsExporter {
-    /// Create a new metrics exporter
-    pub fn new(client: impl MetricsClient, temporality: Temporality) -> MetricsExporter {
+    /// Create a new metrics exporter driven by the given [TemporalitySelector].
+    pub fn new(
+        client: impl MetricsClient,
+        temporality_selector: Box<dyn TemporalitySelector>,
+    ) -> MetricsExporter {
         MetricsExporter {
             client: Box::new(client),
-            temporality,
+            temporality_selector,
+            retry_policy: None,
+            sleep: None,
+            metric_filter: None,
+        }
+    }
+
+    /// Drop metric streams rejected by `metric_filter` before export.
+    pub(crate) fn with_metric_filter(mut self, metric_filter: Box<dyn MetricFilter>) -> MetricsExporter {
+        self.metric_filter = Some(metric_filter);
+        self
+    }
+
+    /// The [Temporality] this exporter requests for the given [InstrumentKind].
+    pub fn temporality_for(&self, kind: InstrumentKind) -> Temporality {
+        self.temporality_selector.temporality(kind)
+    }
+
+    /// Wrap this exporter with a [RetryPolicy], using `sleep` (backed by the
+    /// pipeline [`Runtime`]'s timer) to wait between attempts.
+    pub(crate) fn with_retry_policy<F>(mut self, retry_policy: RetryPolicy, sleep: F) -> MetricsExporter
+    where
+        F: Fn(time::Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync + 'static,
+    {
+        self.retry_policy = Some(retry_policy);
+        self.sleep = Some(Box::new(sleep));
+        self
+    }
+
+    async fn export_with_retry(
+        &self,
+        policy: &RetryPolicy,
+        sleep: &SleepFn,
+        metrics: &mut ResourceMetrics,
+    ) -> Result<()> {
+        let start = time::Instant::now();
+        let mut attempt: u32 = 0;
+        loop {
+            let err = match self.client.export(metrics).await {
+                Ok(partial_success) => {
+                    handle_partial_success(partial_success);
+                    return Ok(());
+                }
+                Err(err) => err,
+            };
+
+            attempt += 1;
+            let retry_after = match classify_error(&err) {
+                RetryDecision::Permanent => return Err(err),
+                RetryDecision::Retryable(retry_after) => retry_after,
+            };
+
+            if attempt as usize >= policy.max_attempts || start.elapsed() >= policy.max_elapsed_time {
+                return Err(err);
+            }
+
+            // Honor a server-provided `Retry-After` as-is (waiting at least that
+            // long); otherwise back off exponentially with full jitter. Jitter
+            // is applied only to the computed backoff so it never shortens a
+            // `Retry-After`.
+            let delay = match retry_after {
+                Some(retry_after) => retry_after,
+                None => full_jitter(backoff_delay(policy, attempt - 1)),
+            };
+
+            // Do not sleep past the overall time budget, and give up rather than
+            // retry before a `Retry-After` that ends beyond it.
+            let remaining = policy.max_elapsed_time.saturating_sub(start.elapsed());
+            if remaining.is_zero() || matches!(retry_after, Some(retry_after) if retry_after > remaining) {
+                return Err(err);
+            }
+            sleep(delay.min(remaining)).await;
+        }
+    }
+}
+
+/// Drop every metric stream the `filter` rejects, removing now-empty scopes so
+/// they are never serialized.
+fn apply_metric_filter(filter: &dyn MetricFilter, metrics: &mut ResourceMetrics) {
+    metrics.scope_metrics.retain_mut(|scope_metrics| {
+        let scope_name = scope_metrics.scope.name.to_string();
+        scope_metrics
+            .metrics
+            .retain(|metric| filter.keep(&scope_name, metric.name.as_ref()));
+        !scope_metrics.metrics.is_empty()
+    });
+}
+
+/// Whether an export error should be retried, and any server-requested delay.
+enum RetryDecision {
+    /// Retry after the optional `Retry-After` duration.
+    Retryable(Option<time::Duration>),
+    /// Do not retry.
+    Permanent,
+}
+
+/// gRPC status codes OTLP treats as transient, matched as whole tokens.
+const RETRYABLE_GRPC_CODES: [&str; 3] = ["unavailable", "aborted", "exhausted"];
+/// HTTP status codes OTLP treats as transient, matched as whole numeric tokens.
+const RETRYABLE_HTTP_CODES: [&str; 4] = ["429", "502", "503", "504"];
+/// Transport/connection failure phrases that indicate a transient outage (a
+/// collector that is momentarily unreachable), matched as substrings. These are
+/// specific multi-word phrases rather than bare words like "connection" so that
+/// permanent errors whose prose merely mentions a connection are not retried.
+const RETRYABLE_TRANSPORT_PHRASES: [&str; 7] = [
+    "connection refused",
+    "connection reset",
+    "connection closed",
+    "broken pipe",
+    "transport error",
+    "tcp connect error",
+    "error trying to connect",
+];
+
+/// Classify the error returned by [MetricsClient::export] as retryable or permanent.
+///
+/// Ideally this would branch on the transport's status code directly, but the
+/// client erases it into an opaque [`opentelemetry::metrics::MetricsError`] on
+/// this SDK version. To stay as robust as the rendered text allows:
+///
+/// * the message is split into case-insensitive alphanumeric *tokens* and
+///   matched against the transient gRPC codes ([`RETRYABLE_GRPC_CODES`]) and
+///   HTTP statuses ([`RETRYABLE_HTTP_CODES`]) — whole-token matching means `429`
+///   is recognized with or without a leading space but `4290` is not; and
+/// * connection/transport outages (the motivating "momentary collector outage")
+///   are matched against the specific phrases in
+///   [`RETRYABLE_TRANSPORT_PHRASES`], so `"tcp connect error"` retries while a
+///   permanent error that merely contains the word "connection" does not.
+///
+/// See the tests for the exact message formats this relies on.
+fn classify_error(err: &opentelemetry::metrics::MetricsError) -> RetryDecision {
+    let message = err.to_string();
+    let lower = message.to_ascii_lowercase();
+
+    let status_retryable = message
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .any(|token| {
+            RETRYABLE_GRPC_CODES
+                .iter()
+                .any(|code| token.eq_ignore_ascii_case(code))
+                || RETRYABLE_HTTP_CODES.contains(&token)
+        });
+
+    let transport_retryable = RETRYABLE_TRANSPORT_PHRASES
+        .iter()
+        .any(|phrase| lower.contains(phrase));
+
+    if status_retryable || transport_retryable {
+        RetryDecision::Retryable(parse_retry_after(&message))
+    } else {
+        RetryDecision::Permanent
+    }
+}
+
+/// Parse a `Retry-After` delay (in seconds) out of an error message, if present.
+fn parse_retry_after(message: &str) -> Option<time::Duration> {
+    let lower = message.to_ascii_lowercase();
+    let idx = lower.find("retry-after")?;
+    let rest = &message[idx + "retry-after".len()..];
+    let secs: u64 = rest
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .ok()?;
+    Some(time::Duration::from_secs(secs))
+}
+
+/// `min(max_interval, initial_interval * multiplier^attempt)`.
+fn backoff_delay(policy: &RetryPolicy, attempt: u32) -> time::Duration {
+    let scaled = policy.initial_interval.as_secs_f64() * policy.multiplier.powi(attempt as i32);
+    let capped = scaled.min(policy.max_interval.as_secs_f64());
+    time::Duration::from_secs_f64(capped)
+}
+
+/// Full jitter: pick a uniformly random duration in `[0, delay]`.
+///
+/// A tiny, dependency-free PRNG seeded from the wall clock is enough here: the
+/// jitter only needs to de-correlate retries across processes, not to be
+/// cryptographically sound.
+fn full_jitter(delay: time::Duration) -> time::Duration {
+    let nanos = delay.as_nanos() as u64;
+    if nanos == 0 {
+        return delay;
+    }
+    let seed = time::SystemTime::now()
+        .duration_since(time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    // splitmix64
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    time::Duration::from_nanos(z % (nanos + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use opentelemetry::metrics::MetricsError;
+
+    fn is_retryable(message: &str) -> bool {
+        matches!(
+            classify_error(&MetricsError::Other(message.to_string())),
+            RetryDecision::Retryable(_)
+        )
+    }
+
+    #[test]
+    fn classify_error_matches_transient_status_tokens() {
+        // gRPC codes, however the transport renders them.
+        assert!(is_retryable("status: Unavailable, message: \"connection refused\""));
+        assert!(is_retryable("grpc status: RESOURCE_EXHAUSTED"));
+        assert!(is_retryable("the call was ABORTED by the server"));
+        // HTTP statuses, after punctuation and on their own.
+        assert!(is_retryable("HTTP status client error (429 Too Many Requests)"));
+        assert!(is_retryable("503"));
+    }
+
+    #[test]
+    fn classify_error_retries_connection_and_transport_outages() {
+        // The motivating "momentary collector outage": transport/connection
+        // failures that carry no gRPC/HTTP status token must still retry.
+        assert!(is_retryable("tcp connect error: Connection refused (os error 111)"));
+        assert!(is_retryable("transport error"));
+        assert!(is_retryable("error trying to connect: connection reset by peer"));
+    }
+
+    #[test]
+    fn classify_error_rejects_permanent_and_substring_traps() {
+        assert!(!is_retryable("status: InvalidArgument, message: \"bad request\""));
+        // Prose that merely contains the bare word "connection" must not retry.
+        assert!(!is_retryable("permanent failure: connection schema invalid"));
+        // A number that embeds a status code is not that status code.
+        assert!(!is_retryable("error 4290 is fatal"));
+    }
+
+    #[test]
+    fn parse_retry_after_reads_seconds() {
+        assert_eq!(
+            parse_retry_after("rejected, Retry-After: 12 seconds"),
+            Some(time::Duration::from_secs(12))
+        );
+        assert_eq!(parse_retry_after("no hint here"), None);
+    }
+
+    #[test]
+    fn backoff_delay_grows_and_caps() {
+        let policy = RetryPolicy::default();
+        assert_eq!(backoff_delay(&policy, 0), policy.initial_interval);
+        assert!(backoff_delay(&policy, 1) > backoff_delay(&policy, 0));
+        // Never exceeds max_interval.
+        assert_eq!(backoff_delay(&policy, 100), policy.max_interval);
+    }
+
+    #[test]
+    fn full_jitter_stays_within_bounds() {
+        let delay = time::Duration::from_millis(500);
+        assert!(full_jitter(delay) <= delay);
+        assert_eq!(full_jitter(time::Duration::ZERO), time::Duration::ZERO);
+    }
+
+    #[test]
+    fn prefix_allow_list_keeps_matching_prefixes() {
+        let filter = PrefixAllowList::new(["http.", "db."]);
+        assert!(filter.keep("scope", "http.server.duration"));
+        assert!(filter.keep("scope", "db.client.calls"));
+        assert!(!filter.keep("scope", "runtime.memory"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_deny_list_drops_matching_names() {
+        let filter = RegexDenyList::new([r"^debug\.", r"_bucket$"]).unwrap();
+        assert!(!filter.keep("scope", "debug.trace"));
+        assert!(!filter.keep("scope", "latency_bucket"));
+        assert!(filter.keep("scope", "http.requests"));
+    }
+
+    #[test]
+    fn constant_selectors_are_uniform() {
+        for kind in [
+            InstrumentKind::Counter,
+            InstrumentKind::UpDownCounter,
+            InstrumentKind::Histogram,
+            InstrumentKind::Gauge,
+        ] {
+            assert_eq!(all_cumulative().temporality(kind), Temporality::Cumulative);
+            assert_eq!(all_delta().temporality(kind), Temporality::Delta);
         }
     }
+
+    #[test]
+    fn partial_success_reports_rejected_count() {
+        assert_eq!(handle_partial_success(PartialSuccess::default()), 0);
+        assert_eq!(
+            handle_partial_success(PartialSuccess {
+                rejected_data_points: 7,
+                error_message: "cardinality limit".to_string(),
+            }),
+            7
+        );
+    }
 }